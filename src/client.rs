@@ -11,10 +11,11 @@ use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::TcpStream;
 use tokio::sync::RwLock;
 use tokio::time::timeout;
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, trace, warn};
 
 use crate::DynStream;
 use crate::config::Platform;
+use crate::flv::VideoFrameType;
 use crate::server::handshake_and_create_server_session;
 
 async fn perform_client_handshake(
@@ -233,40 +234,13 @@ pub async fn handle_publisher(
                     ServerSessionEvent::VideoDataReceived {
                         data, timestamp, ..
                     } => {
+                        trace!(
+                            "Video tag {:?} ts={}",
+                            VideoFrameType::from_tag(&data),
+                            timestamp.value
+                        );
                         for pc in push_clients.iter() {
-                            if *pc.publish_ready_rx.borrow() {
-                                let mut state = pc.client_state.write().await;
-                                match state.session.publish_video_data(
-                                    data.clone(),
-                                    timestamp,
-                                    true,
-                                ) {
-                                    Ok(ClientSessionResult::OutboundResponse(packet)) => {
-                                        if let Err(e) =
-                                            pc.tx_feed.try_send(Bytes::from(packet.bytes.clone()))
-                                        {
-                                            debug!(
-                                                "Dropped publish_video_data packet for push client: {}",
-                                                e
-                                            );
-                                        }
-                                    }
-                                    Ok(_) => {}
-                                    Err(e) => {
-                                        error!("Error publish_video_data to push client: {:?}", e);
-                                    }
-                                }
-                                drop(state);
-                            } else {
-                                let mut state = pc.client_state.write().await;
-                                if state.prepublish_video_buffer.len() < 128 {
-                                    state.prepublish_video_buffer.push_back(data.clone());
-                                } else {
-                                    state.prepublish_video_buffer.pop_front();
-                                    state.prepublish_video_buffer.push_back(data.clone());
-                                }
-                                drop(state);
-                            }
+                            pc.send_video(&data, timestamp).await;
                         }
                     }
 
@@ -1,8 +1,10 @@
 use std::collections::VecDeque;
 use std::panic::{AssertUnwindSafe, catch_unwind};
 use std::sync::Arc;
+use std::time::Duration;
 
 use bytes::Bytes;
+use rml_rtmp::chunk_io::Packet;
 use rml_rtmp::sessions::{
     ClientSession, ClientSessionConfig, ClientSessionEvent, ClientSessionResult,
     PublishRequestType, StreamMetadata,
@@ -17,8 +19,16 @@ use url::Url;
 
 use crate::DynStream;
 use crate::client::perform_client_handshake;
+use crate::flv::{VideoFrameType, video_tag_can_be_dropped};
+
+/// How long a packet that must not be dropped may wait for room in a full feed
+const FEED_SEND_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// Free slots left in a push client's feed below which disposable frames are skipped
+const DISPOSABLE_SKIP_THRESHOLD: usize = 32;
 
 pub struct PushClient {
+    pub(crate) addr: String,
     pub(crate) tx_feed: mpsc::Sender<Bytes>, // bounded to avoid unbounded memory growth
     pub(crate) client_state: Arc<RwLock<ClientStateWrapper>>,
     pub(crate) publish_ready_rx: watch::Receiver<bool>,
@@ -30,6 +40,51 @@ pub struct ClientStateWrapper {
     pub prepublish_video_buffer: VecDeque<Bytes>,
     pub prepublish_audio_buffer: VecDeque<Bytes>,
     pub prepublish_metadata: Option<StreamMetadata>,
+    pub disposable_skip: DisposableSkip,
+}
+
+/// Sheds disposable inter frames while a push client's feed is saturated. They
+/// are never referenced by later frames, so skipping them keeps the stream
+/// decodable, unlike try_send on a full feed which drops whatever comes next.
+#[derive(Debug, Default)]
+pub struct DisposableSkip {
+    saturated: bool,
+    skipped: u64,
+}
+
+impl DisposableSkip {
+    pub fn saturated(&self) -> bool {
+        self.saturated
+    }
+
+    /// Total disposable frames skipped for this push client
+    pub fn skipped(&self) -> u64 {
+        self.skipped
+    }
+
+    /// Record the feed's free slots for this tag and whether it should be skipped
+    pub fn should_skip(&mut self, frame_type: Option<VideoFrameType>, free_slots: usize) -> bool {
+        self.saturated = free_slots < DISPOSABLE_SKIP_THRESHOLD;
+        let skip = self.saturated && frame_type == Some(VideoFrameType::DisposableInter);
+        if skip {
+            self.skipped += 1;
+        }
+        skip
+    }
+}
+
+/// Queue a packet for the writer task. Droppable packets are discarded when the
+/// feed is full; the rest get a short grace period so keyframes and sequence
+/// headers survive a brief burst without stalling the relay on a dead target.
+async fn feed_packet(tx: &mpsc::Sender<Bytes>, packet: Packet) -> Result<(), String> {
+    let bytes = Bytes::from(packet.bytes);
+    if packet.can_be_dropped {
+        tx.try_send(bytes).map_err(|e| e.to_string())
+    } else {
+        tx.send_timeout(bytes, FEED_SEND_TIMEOUT)
+            .await
+            .map_err(|e| e.to_string())
+    }
 }
 
 impl PushClient {
@@ -136,6 +191,7 @@ impl PushClient {
             prepublish_video_buffer: VecDeque::new(),
             prepublish_audio_buffer: VecDeque::new(),
             prepublish_metadata: None,
+            disposable_skip: DisposableSkip::default(),
         };
 
         let client_state = Arc::new(RwLock::new(client_state));
@@ -214,12 +270,17 @@ impl PushClient {
                                                             }
                                                         }
 
-                                                        // drain buffered video
+                                                        // drain buffered video without awaiting: the session lock is held
                                                         while let Some(vframe) = state.prepublish_video_buffer.pop_front() {
-                                                            match state.session.publish_video_data(vframe.clone(), RtmpTimestamp::new(0), true) {
+                                                            let can_be_dropped = video_tag_can_be_dropped(&vframe);
+                                                            match state.session.publish_video_data(vframe, RtmpTimestamp::new(0), can_be_dropped) {
                                                                 Ok(ClientSessionResult::OutboundResponse(packet)) => {
-                                                                    if let Err(e) = tx_clone.try_send(Bytes::from(packet.bytes.clone())) {
-                                                                        debug!("Dropped buffered video packet for {}: {}", addr_clone, e);
+                                                                    if let Err(e) = tx_clone.try_send(Bytes::from(packet.bytes)) {
+                                                                        if can_be_dropped {
+                                                                            debug!("Dropped buffered video packet for {}: {}", addr_clone, e);
+                                                                        } else {
+                                                                            warn!("Dropped buffered keyframe for {}: {}", addr_clone, e);
+                                                                        }
                                                                     }
                                                                 }
                                                                 Ok(_) => {}
@@ -284,9 +345,124 @@ impl PushClient {
         }
 
         Ok(PushClient {
+            addr,
             tx_feed: tx,
             client_state,
             publish_ready_rx,
         })
     }
+
+    /// Publish a video tag to the push target, or buffer it until publish is accepted
+    pub(crate) async fn send_video(&self, data: &Bytes, timestamp: RtmpTimestamp) {
+        if !*self.publish_ready_rx.borrow() {
+            let mut state = self.client_state.write().await;
+            if state.prepublish_video_buffer.len() >= 128 {
+                state.prepublish_video_buffer.pop_front();
+            }
+            state.prepublish_video_buffer.push_back(data.clone());
+            return;
+        }
+
+        let can_be_dropped = video_tag_can_be_dropped(data);
+        let mut state = self.client_state.write().await;
+
+        let was_saturated = state.disposable_skip.saturated();
+        let skip = state
+            .disposable_skip
+            .should_skip(VideoFrameType::from_tag(data), self.tx_feed.capacity());
+        match (was_saturated, state.disposable_skip.saturated()) {
+            (false, true) => warn!(
+                "Push client {} saturado; omitiendo frames desechables",
+                self.addr
+            ),
+            (true, false) => info!(
+                "Push client {} recuperado; {} frames desechables omitidos en total",
+                self.addr,
+                state.disposable_skip.skipped()
+            ),
+            _ => {}
+        }
+        if skip {
+            return;
+        }
+
+        let result = state
+            .session
+            .publish_video_data(data.clone(), timestamp, can_be_dropped);
+        // release the session before waiting on the feed so the reader task keeps going
+        drop(state);
+
+        match result {
+            Ok(ClientSessionResult::OutboundResponse(packet)) => {
+                if let Err(e) = feed_packet(&self.tx_feed, packet).await {
+                    if can_be_dropped {
+                        debug!("Dropped publish_video_data packet for {}: {}", self.addr, e);
+                    } else {
+                        warn!("Dropped keyframe for {}: {}", self.addr, e);
+                    }
+                }
+            }
+            Ok(_) => {}
+            Err(e) => {
+                error!("Error publish_video_data to push client: {:?}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_only_disposable_frames_while_saturated() {
+        let mut skip = DisposableSkip::default();
+        let loaded = DISPOSABLE_SKIP_THRESHOLD - 1;
+
+        assert!(!skip.should_skip(Some(VideoFrameType::DisposableInter), 256));
+        assert!(!skip.saturated());
+
+        assert!(skip.should_skip(Some(VideoFrameType::DisposableInter), loaded));
+        assert!(!skip.should_skip(Some(VideoFrameType::Inter), loaded));
+        assert!(!skip.should_skip(Some(VideoFrameType::Key), loaded));
+        assert!(!skip.should_skip(None, loaded));
+        assert!(skip.saturated());
+        assert_eq!(skip.skipped(), 1);
+
+        assert!(!skip.should_skip(
+            Some(VideoFrameType::DisposableInter),
+            DISPOSABLE_SKIP_THRESHOLD
+        ));
+        assert!(!skip.saturated());
+        assert_eq!(skip.skipped(), 1);
+    }
+
+    #[tokio::test]
+    async fn send_video_skips_disposable_frame_on_saturated_feed() {
+        let (session, _) = ClientSession::new(ClientSessionConfig::new()).unwrap();
+        let (tx, mut rx) = mpsc::channel::<Bytes>(1);
+        let (_ready_tx, publish_ready_rx) = watch::channel(true);
+        let pc = PushClient {
+            addr: "test:1935".to_string(),
+            tx_feed: tx,
+            client_state: Arc::new(RwLock::new(ClientStateWrapper {
+                session,
+                target_stream: String::new(),
+                prepublish_video_buffer: VecDeque::new(),
+                prepublish_audio_buffer: VecDeque::new(),
+                prepublish_metadata: None,
+                disposable_skip: DisposableSkip::default(),
+            })),
+            publish_ready_rx,
+        };
+
+        pc.send_video(&Bytes::from_static(&[0x37, 0x01]), RtmpTimestamp::new(0))
+            .await;
+        assert_eq!(pc.client_state.read().await.disposable_skip.skipped(), 1);
+        assert!(rx.try_recv().is_err());
+
+        pc.send_video(&Bytes::from_static(&[0x27, 0x01]), RtmpTimestamp::new(40))
+            .await;
+        assert_eq!(pc.client_state.read().await.disposable_skip.skipped(), 1);
+    }
 }
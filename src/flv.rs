@@ -0,0 +1,95 @@
+/// FLV video tag `frame_type`, taken from the upper nibble of the first tag byte
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VideoFrameType {
+    Key,
+    Inter,
+    DisposableInter,
+    GeneratedKey,
+    Command,
+}
+
+impl VideoFrameType {
+    /// Read the frame type from the first tag byte; `None` for an empty tag or a reserved value
+    pub fn from_tag(data: &[u8]) -> Option<Self> {
+        // enhanced RTMP uses the top bit as IsExHeader, frame type stays in the next 3 bits
+        match (data.first()? >> 4) & 0x07 {
+            1 => Some(Self::Key),
+            2 => Some(Self::Inter),
+            3 => Some(Self::DisposableInter),
+            4 => Some(Self::GeneratedKey),
+            5 => Some(Self::Command),
+            _ => None,
+        }
+    }
+
+    /// Keyframes (and the sequence headers sent as keyframes) and command frames
+    /// must reach the remote; everything else can be shed when it falls behind.
+    pub fn can_be_dropped(self) -> bool {
+        matches!(self, Self::Inter | Self::DisposableInter)
+    }
+}
+
+/// Whether a video tag may be dropped on the way to a push target; tags with an
+/// unknown frame type keep the relay's old behaviour and are droppable.
+pub fn video_tag_can_be_dropped(data: &[u8]) -> bool {
+    VideoFrameType::from_tag(data).is_none_or(VideoFrameType::can_be_dropped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_tag_reads_each_frame_type() {
+        assert_eq!(VideoFrameType::from_tag(&[0x17]), Some(VideoFrameType::Key));
+        assert_eq!(
+            VideoFrameType::from_tag(&[0x27]),
+            Some(VideoFrameType::Inter)
+        );
+        assert_eq!(
+            VideoFrameType::from_tag(&[0x37]),
+            Some(VideoFrameType::DisposableInter)
+        );
+        assert_eq!(
+            VideoFrameType::from_tag(&[0x47]),
+            Some(VideoFrameType::GeneratedKey)
+        );
+        assert_eq!(
+            VideoFrameType::from_tag(&[0x57]),
+            Some(VideoFrameType::Command)
+        );
+    }
+
+    #[test]
+    fn from_tag_rejects_unknown_and_empty() {
+        assert_eq!(VideoFrameType::from_tag(&[0x07]), None);
+        assert_eq!(VideoFrameType::from_tag(&[0x67]), None);
+        assert_eq!(VideoFrameType::from_tag(&[0x77]), None);
+        assert_eq!(VideoFrameType::from_tag(&[]), None);
+    }
+
+    #[test]
+    fn from_tag_ignores_enhanced_rtmp_ex_header_bit() {
+        assert_eq!(VideoFrameType::from_tag(&[0x90]), Some(VideoFrameType::Key));
+        assert_eq!(
+            VideoFrameType::from_tag(&[0xA1]),
+            Some(VideoFrameType::Inter)
+        );
+    }
+
+    #[test]
+    fn only_inter_frames_can_be_dropped() {
+        assert!(!VideoFrameType::Key.can_be_dropped());
+        assert!(VideoFrameType::Inter.can_be_dropped());
+        assert!(VideoFrameType::DisposableInter.can_be_dropped());
+        assert!(!VideoFrameType::GeneratedKey.can_be_dropped());
+        assert!(!VideoFrameType::Command.can_be_dropped());
+    }
+
+    #[test]
+    fn unknown_video_tags_can_be_dropped() {
+        assert!(video_tag_can_be_dropped(&[]));
+        assert!(video_tag_can_be_dropped(&[0x67]));
+        assert!(!video_tag_can_be_dropped(&[0x17]));
+    }
+}
@@ -11,6 +11,7 @@ use tracing_subscriber::filter::LevelFilter;
 mod client;
 mod config;
 mod error;
+mod flv;
 mod provider;
 mod server;
 